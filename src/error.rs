@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use chromiumoxide::error::CdpError;
 
+use crate::cursor_persist::PersistCheckpointError;
 use crate::header_persist::PersistHeadersError;
 
 #[derive(Debug)]
@@ -14,6 +15,10 @@ pub enum Error {
     BadStatus(u16),
     Network(String),
     PersistHeaders(PersistHeadersError),
+    OAuth(String),
+    Media(String),
+    PersistCheckpoint(PersistCheckpointError),
+    IncompleteThread(u128),
 }
 
 impl Display for Error {
@@ -27,6 +32,14 @@ impl Display for Error {
             Self::BadStatus(c) => write!(f, "api returned status code: {}", c),
             Self::Network(s) => write!(f, "network error: {}", s),
             Self::PersistHeaders(e) => write!(f, "{}", e),
+            Self::OAuth(s) => write!(f, "OAuth error: {}", s),
+            Self::Media(s) => write!(f, "media download error: {}", s),
+            Self::PersistCheckpoint(e) => write!(f, "{}", e),
+            Self::IncompleteThread(id) => write!(
+                f,
+                "could not walk up to the conversation root: lookup of ancestor tweet {} failed",
+                id
+            ),
         }
     }
 }
@@ -1,29 +1,88 @@
+mod cursor_persist;
+mod error;
+mod header_persist;
+mod media;
+mod model;
+mod oauth;
+mod scraper;
+
+pub use error::Error;
+pub use media::{FileStorage, MediaDownloader, MediaStorage};
+pub use model::{Entities, HashtagEntity, Media, Tweet, UrlEntity, User, VideoInfo, VideoVariant};
+pub use oauth::OAuth1Credentials;
+pub use scraper::{AuthMode, HeaderPersist, RetryConfig, TweetScraper};
+
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use chromiumoxide::cdp::browser_protocol::fetch;
 use chromiumoxide::cdp::browser_protocol::network::CookieParam;
 use chromiumoxide::{Browser, BrowserConfig, Page};
 use futures_util::stream::StreamExt;
+use rand::Rng;
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use url::Url;
 
+const MAX_ATTEMPTS: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// One-shot helper that scrapes a single page of results for `username` using a
+/// fresh, fully browser-driven session. Prefer [`TweetScraper`] for anything
+/// that needs pagination, retries, or persisted headers.
+///
+/// If the CDP session dies or a navigation fails mid-request, the browser and its request
+/// interception are torn down and rebuilt from scratch, up to `MAX_ATTEMPTS` times, with
+/// exponential backoff between attempts.
 pub async fn fetch_tweets(username: &str) -> Result<()> {
+    let query = format!("from:{username} since:2023-02-01 filter:images");
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = fetch_tweets_once(&query).await;
+
+        match result {
+            Ok(json) => {
+                println!("{json}");
+                return Ok(());
+            }
+            Err(e) if attempt < MAX_ATTEMPTS => {
+                eprintln!(
+                    "attempt {attempt}/{MAX_ATTEMPTS} failed: {e:#}, retrying after backoff"
+                );
+                tokio::time::sleep(backoff_delay(attempt)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Run a single browser/interception/query attempt, racing the query itself against any
+/// failure surfaced by the interceptor so a broken CDP session doesn't just hang forever.
+async fn fetch_tweets_once(query: &str) -> Result<String> {
     let (mut browser, browser_handle) = setup_browser().await?;
-    let (page, intercept_handle) = setup_interception(&mut browser).await?;
+    let (page, intercept_handle, mut failures) = setup_interception(&mut browser).await?;
 
-    let json = query_twitter(
-        page.clone(),
-        format!("from:{username} since:2023-02-01 filter:images"),
-    )
-    .await?;
-    println!("{json}");
+    let result = tokio::select! {
+        result = query_twitter(page.clone(), query) => result,
+        Some(e) = failures.recv() => Err(e),
+    };
 
     browser.close().await?;
     let _ = browser_handle.await;
     let _ = intercept_handle.await;
 
-    Ok(())
+    result
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    let exponential = BASE_BACKOFF.saturating_mul(1u32 << attempt.min(6));
+    let capped = exponential.min(MAX_BACKOFF);
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=capped.as_millis() as u64 / 4));
+    capped + jitter
 }
 
 async fn setup_browser() -> Result<(Browser, JoinHandle<()>)> {
@@ -42,8 +101,12 @@ async fn setup_browser() -> Result<(Browser, JoinHandle<()>)> {
     Ok((browser, browser_handle))
 }
 
-/// Setup request interception to add headers
-async fn setup_interception(browser: &mut Browser) -> Result<(Arc<Page>, JoinHandle<()>)> {
+/// Setup request interception to add headers. Failures inside the interception task are sent
+/// over the returned channel instead of panicking, so callers can surface them as a clean
+/// `Result` and decide whether to retry.
+async fn setup_interception(
+    browser: &mut Browser,
+) -> Result<(Arc<Page>, JoinHandle<()>, mpsc::UnboundedReceiver<anyhow::Error>)> {
     let page = Arc::new(
         browser
             .start_incognito_context()
@@ -104,58 +167,78 @@ async fn setup_interception(browser: &mut Browser) -> Result<(Arc<Page>, JoinHan
     })
     .await?;
 
-    let mut request_paused = page
-        .event_listener::<fetch::EventRequestPaused>()
-        .await
-        .unwrap();
+    let mut request_paused = page.event_listener::<fetch::EventRequestPaused>().await?;
     let intercept_page = page.clone();
+    let (failure_tx, failure_rx) = mpsc::unbounded_channel();
     let intercept_handle = tokio::task::spawn(async move {
         while let Some(event) = request_paused.next().await {
-            match (*event).clone() {
-                fetch::EventRequestPaused {
-                    response_status_code: Some(status_code),
-                    ..
-                } => {
-                    let headers: Vec<fetch::HeaderEntry> = Vec::new();
-                    let f = fetch::FulfillRequestParams::builder()
-                        .request_id(event.request_id.clone())
-                        .response_headers(headers)
-                        .response_code(status_code)
-                        .build()
-                        .unwrap();
-                    intercept_page.execute(f).await.unwrap();
-                }
-                _ => {
-                    let mut headers = vec![];
-                    for (k, v) in event.request.headers.inner().as_object().unwrap() {
-                        headers.push(fetch::HeaderEntry {
-                            name: k.clone(),
-                            value: v.as_str().unwrap().into(),
-                        })
-                    }
-                    let he = fetch::HeaderEntry {
-                        name: "authorization".into(),
-                        value: "Bearer AAAAAAAAAAAAAAAAAAAAANRILgAAAAAAnNwIzUejRCOuH5E6I8xnZz4puTs%3D1Zv7ttfk8LF81IUq16cHjhLTvJu4FA33AGWWjCpTnA".into()
-                    };
-                    headers.push(he);
-                    let he = fetch::HeaderEntry {
-                        name: "x-guest-token".into(),
-                        value: guest_token.clone().into(),
-                    };
-                    headers.push(he);
-
-                    let c = fetch::ContinueRequestParams::builder()
-                        .request_id(event.request_id.clone())
-                        .headers(headers)
-                        .build()
-                        .unwrap();
-                    intercept_page.execute(c).await.unwrap();
-                }
+            if let Err(e) = handle_request_paused(&intercept_page, &guest_token, &event).await {
+                let _ = failure_tx.send(e);
+                break;
             }
         }
     });
 
-    Ok((page, intercept_handle))
+    Ok((page, intercept_handle, failure_rx))
+}
+
+/// Continue (with injected auth headers) or fulfill a single intercepted request, returning
+/// an error instead of panicking on CDP failures so the caller can retry.
+async fn handle_request_paused(
+    page: &Page,
+    guest_token: &str,
+    event: &fetch::EventRequestPaused,
+) -> Result<()> {
+    match event.clone() {
+        fetch::EventRequestPaused {
+            response_status_code: Some(status_code),
+            ..
+        } => {
+            let headers: Vec<fetch::HeaderEntry> = Vec::new();
+            let f = fetch::FulfillRequestParams::builder()
+                .request_id(event.request_id.clone())
+                .response_headers(headers)
+                .response_code(status_code)
+                .build()
+                .map_err(|s| anyhow!(s))?;
+            page.execute(f).await?;
+        }
+        _ => {
+            let mut headers = vec![];
+            let request_headers = event
+                .request
+                .headers
+                .inner()
+                .as_object()
+                .ok_or_else(|| anyhow!("request headers were not a JSON object"))?;
+            for (k, v) in request_headers {
+                let value = v
+                    .as_str()
+                    .ok_or_else(|| anyhow!("header {k} value was not a string"))?;
+                headers.push(fetch::HeaderEntry {
+                    name: k.clone(),
+                    value: value.into(),
+                })
+            }
+            headers.push(fetch::HeaderEntry {
+                name: "authorization".into(),
+                value: "Bearer AAAAAAAAAAAAAAAAAAAAANRILgAAAAAAnNwIzUejRCOuH5E6I8xnZz4puTs%3D1Zv7ttfk8LF81IUq16cHjhLTvJu4FA33AGWWjCpTnA".into()
+            });
+            headers.push(fetch::HeaderEntry {
+                name: "x-guest-token".into(),
+                value: guest_token.into(),
+            });
+
+            let c = fetch::ContinueRequestParams::builder()
+                .request_id(event.request_id.clone())
+                .headers(headers)
+                .build()
+                .map_err(|s| anyhow!(s))?;
+            page.execute(c).await?;
+        }
+    }
+
+    Ok(())
 }
 
 async fn query_twitter(page: Arc<Page>, query: impl AsRef<str>) -> Result<String> {
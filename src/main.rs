@@ -4,11 +4,18 @@ use std::process::ExitCode;
 
 use clap::Parser;
 use futures_util::stream::StreamExt;
-use tweet_scraper::{HeaderPersist, TweetScraper};
+use tweet_scraper::{
+    AuthMode, FileStorage, HeaderPersist, MediaDownloader, OAuth1Credentials, RetryConfig,
+    TweetScraper,
+};
+
+/// Maximum number of media files downloaded concurrently when `--media-dir` is set.
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
 
 #[derive(Parser)]
 struct Args {
-    query: String,
+    /// Search query. Not used with --tweet-id, --user, or --thread-id.
+    query: Option<String>,
 
     #[arg(short, long)]
     limit: Option<usize>,
@@ -16,11 +23,50 @@ struct Args {
     #[arg(short, long)]
     min_id: Option<u128>,
 
+    /// Look up a single tweet by id instead of running a search
+    #[arg(long, conflicts_with_all = ["user", "thread_id", "limit", "min_id"])]
+    tweet_id: Option<u128>,
+
+    /// Look up a single user profile by screen name or id instead of running a search
+    #[arg(long, conflicts_with_all = ["tweet_id", "thread_id", "limit", "min_id"])]
+    user: Option<String>,
+
+    /// Reconstruct the conversation thread containing this tweet id instead of running a search
+    #[arg(long, conflicts_with_all = ["tweet_id", "user", "limit", "min_id"])]
+    thread_id: Option<u128>,
+
     #[arg(long)]
     save_headers: Option<PathBuf>,
 
     #[arg(long, conflicts_with = "save_headers")]
     load_headers: Option<PathBuf>,
+
+    /// OAuth 1.0a consumer key, for authenticated scraping instead of a guest token
+    #[arg(long, requires_all = ["consumer_secret", "access_token", "access_token_secret"])]
+    consumer_key: Option<String>,
+
+    #[arg(long)]
+    consumer_secret: Option<String>,
+
+    #[arg(long)]
+    access_token: Option<String>,
+
+    #[arg(long)]
+    access_token_secret: Option<String>,
+
+    /// Download photo/video media attached to matched tweets into this directory
+    #[arg(long)]
+    media_dir: Option<PathBuf>,
+
+    /// Checkpoint search pagination progress to this file after every page, so a killed or
+    /// crashed scrape can be continued with --resume-checkpoint
+    #[arg(long)]
+    save_checkpoint: Option<PathBuf>,
+
+    /// Resume a search from a checkpoint previously written via --save-checkpoint, continuing
+    /// from its saved cursor instead of restarting from the top of the timeline
+    #[arg(long, conflicts_with_all = ["tweet_id", "user", "thread_id", "min_id", "save_checkpoint"])]
+    resume_checkpoint: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -35,7 +81,25 @@ async fn main() -> ExitCode {
         HeaderPersist::None
     };
 
-    let mut scraper = match TweetScraper::initialize(header_persist).await {
+    let auth_mode = match (
+        args.consumer_key,
+        args.consumer_secret,
+        args.access_token,
+        args.access_token_secret,
+    ) {
+        (Some(consumer_key), Some(consumer_secret), Some(token), Some(token_secret)) => {
+            AuthMode::OAuth1(OAuth1Credentials {
+                consumer_key,
+                consumer_secret,
+                token,
+                token_secret,
+            })
+        }
+        _ => AuthMode::Guest,
+    };
+
+    let mut scraper = match TweetScraper::initialize(header_persist, auth_mode, RetryConfig::default()).await
+    {
         Ok(s) => s,
         Err(e) => {
             eprintln!("{}", e);
@@ -43,11 +107,78 @@ async fn main() -> ExitCode {
         }
     };
 
-    let tweets_stream = scraper.tweets(args.query, args.limit, args.min_id).await;
+    if let Some(id) = args.tweet_id {
+        return match scraper.tweet_by_id(id).await {
+            Ok(tweet) => {
+                println!("{}", serde_json::to_string(&tweet).unwrap());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(username_or_id) = args.user {
+        let result = if let Ok(id) = username_or_id.parse::<u128>() {
+            scraper.user_by_id(id).await
+        } else {
+            scraper.user_by_handle(username_or_id).await
+        };
+        return match result {
+            Ok(user) => {
+                println!("{}", serde_json::to_string(&user).unwrap());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(id) = args.thread_id {
+        return match scraper.thread(id).await {
+            Ok(thread) => {
+                println!("{}", serde_json::to_string(&thread).unwrap());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let Some(query) = args.query else {
+        eprintln!("a search query, --tweet-id, --user, or --thread-id is required");
+        return ExitCode::FAILURE;
+    };
+
+    let media_downloader = args
+        .media_dir
+        .map(|dir| MediaDownloader::new(FileStorage::new(dir), MAX_CONCURRENT_DOWNLOADS));
+
+    if let Some(checkpoint) = args.resume_checkpoint {
+        if let Err(e) = scraper.resume(checkpoint).await {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    } else if let Some(checkpoint) = args.save_checkpoint {
+        scraper.checkpoint_to(checkpoint);
+    }
+
+    let tweets_stream = scraper.tweets(query, args.limit, args.min_id).await;
     futures_util::pin_mut!(tweets_stream);
 
     while let Some(tweet_result) = tweets_stream.next().await {
         let tweet = tweet_result.unwrap();
+
+        if let Some(downloader) = &media_downloader {
+            downloader.download_tweet(&tweet).await;
+        }
+
         if let Err(e) = writeln!(io::stdout(), "{}", serde_json::to_string(&tweet).unwrap()) {
             match e.kind() {
                 io::ErrorKind::BrokenPipe => break,
@@ -0,0 +1,165 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures_util::stream::{self, StreamExt};
+use reqwest::Client;
+use tokio::fs;
+use tokio::sync::Semaphore;
+use url::Url;
+
+use crate::error::Error;
+use crate::model::Tweet;
+
+/// A place to persist downloaded tweet media, so the download loop doesn't need to know
+/// whether files end up on disk, in object storage, or somewhere else entirely.
+#[async_trait::async_trait]
+pub trait MediaStorage: Send + Sync {
+    /// Returns true if this media item has already been saved, so it can be skipped.
+    async fn exists(&self, tweet_id: u128, index: usize, extension: &str) -> bool;
+
+    /// Persist `bytes` for the given tweet/media index.
+    async fn write(
+        &self,
+        tweet_id: u128,
+        index: usize,
+        extension: &str,
+        bytes: &[u8],
+    ) -> Result<(), Error>;
+}
+
+/// Saves media to files named `<tweet_id>_<index>.<extension>` under a directory.
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, tweet_id: u128, index: usize, extension: &str) -> PathBuf {
+        self.dir.join(format!("{tweet_id}_{index}.{extension}"))
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaStorage for FileStorage {
+    async fn exists(&self, tweet_id: u128, index: usize, extension: &str) -> bool {
+        fs::try_exists(self.path(tweet_id, index, extension))
+            .await
+            .unwrap_or(false)
+    }
+
+    async fn write(
+        &self,
+        tweet_id: u128,
+        index: usize,
+        extension: &str,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| Error::Media(e.to_string()))?;
+        fs::write(self.path(tweet_id, index, extension), bytes)
+            .await
+            .map_err(|e| Error::Media(e.to_string()))
+    }
+}
+
+/// Downloads photo/video media attached to tweets, with bounded concurrency across the
+/// whole run and resumability via [`MediaStorage::exists`].
+pub struct MediaDownloader<S> {
+    client: Client,
+    storage: S,
+    semaphore: Arc<Semaphore>,
+}
+
+impl<S: MediaStorage> MediaDownloader<S> {
+    pub fn new(storage: S, max_concurrent_downloads: usize) -> Self {
+        Self {
+            client: Client::new(),
+            storage,
+            semaphore: Arc::new(Semaphore::new(max_concurrent_downloads)),
+        }
+    }
+
+    /// Download every photo/video in `tweet`'s media, skipping files already present and
+    /// recording (rather than propagating) individual failures so the tweet stream keeps going.
+    pub async fn download_tweet(&self, tweet: &Tweet) {
+        let tweet_id = tweet.id as u128;
+
+        stream::iter(media_urls(tweet).into_iter().enumerate())
+            .for_each_concurrent(None, |(index, url)| async move {
+                let Ok(_permit) = self.semaphore.acquire().await else {
+                    return;
+                };
+                if let Err(e) = self.download_one(tweet_id, index, &url).await {
+                    eprintln!(
+                        "failed to download media for tweet {tweet_id} (index {index}): {e}"
+                    );
+                }
+            })
+            .await;
+    }
+
+    async fn download_one(&self, tweet_id: u128, index: usize, url: &str) -> Result<(), Error> {
+        let extension = extension_of(url);
+        if self.storage.exists(tweet_id, index, &extension).await {
+            return Ok(());
+        }
+
+        let bytes = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| Error::Media(e.to_string()))?
+            .bytes()
+            .await
+            .map_err(|e| Error::Media(e.to_string()))?;
+
+        self.storage
+            .write(tweet_id, index, &extension, &bytes)
+            .await
+    }
+}
+
+/// Extracts original-quality photo and highest-bitrate video/animated-gif URLs from a
+/// tweet's `extended_entities` (falling back to `entities`) media array.
+fn media_urls(tweet: &Tweet) -> Vec<String> {
+    let media = tweet
+        .extended_entities
+        .as_ref()
+        .map(|e| &e.media)
+        .filter(|media| !media.is_empty())
+        .unwrap_or(&tweet.entities.media);
+
+    media
+        .iter()
+        .filter_map(|item| match item.media_type.as_str() {
+            "photo" => Some(format!("{}?format=jpg&name=orig", item.media_url_https)),
+            "video" | "animated_gif" => item
+                .video_info
+                .as_ref()
+                .and_then(|info| {
+                    info.variants
+                        .iter()
+                        .filter(|v| v.content_type == "video/mp4")
+                        .max_by_key(|v| v.bitrate.unwrap_or(0))
+                })
+                .map(|v| v.url.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn extension_of(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            Path::new(u.path())
+                .extension()
+                .map(|ext| ext.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "bin".to_owned())
+}
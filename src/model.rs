@@ -0,0 +1,99 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Twitter's `id_str` fields hold the id as a decimal string; a plain number is accepted too,
+/// since that's how these ids serialize back out.
+fn id_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Num(u64),
+        Str(String),
+    }
+    match Id::deserialize(deserializer)? {
+        Id::Num(id) => Ok(id),
+        Id::Str(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// A tweet, resolved from the `global_objects.tweets`/`global_objects.users` maps in a search
+/// response. Use [`crate::TweetScraper::tweets_raw`] instead of [`crate::TweetScraper::tweets`]
+/// if a field isn't modeled here yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tweet {
+    #[serde(rename = "id_str", deserialize_with = "id_from_str")]
+    pub id: u64,
+    #[serde(rename = "full_text", alias = "text")]
+    pub text: String,
+    pub created_at: String,
+    // `parse_tweets` only attaches a `user` object when the author id resolves against
+    // `global_objects.users`, which search results commonly omit for suspended, deleted, or
+    // protected authors. Optional here so those tweets still parse instead of erroring out.
+    #[serde(default)]
+    pub user: Option<User>,
+    #[serde(default)]
+    pub entities: Entities,
+    pub extended_entities: Option<Entities>,
+    #[serde(default, rename = "in_reply_to_status_id_str")]
+    pub in_reply_to_status_id: Option<String>,
+    #[serde(default)]
+    pub quoted_status: Option<Box<Tweet>>,
+    #[serde(default)]
+    pub retweeted_status: Option<Box<Tweet>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    #[serde(rename = "id_str", deserialize_with = "id_from_str")]
+    pub id: u64,
+    pub screen_name: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub followers_count: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Entities {
+    #[serde(default)]
+    pub media: Vec<Media>,
+    #[serde(default)]
+    pub urls: Vec<UrlEntity>,
+    #[serde(default)]
+    pub hashtags: Vec<HashtagEntity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Media {
+    pub id_str: String,
+    #[serde(rename = "type")]
+    pub media_type: String,
+    pub media_url_https: String,
+    #[serde(default)]
+    pub video_info: Option<VideoInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoInfo {
+    pub variants: Vec<VideoVariant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoVariant {
+    #[serde(default)]
+    pub bitrate: Option<u64>,
+    pub content_type: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrlEntity {
+    pub url: String,
+    pub expanded_url: String,
+    pub display_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashtagEntity {
+    pub text: String,
+}
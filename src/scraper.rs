@@ -1,25 +1,40 @@
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use chromiumoxide::cdp::browser_protocol::network::{Cookie, SetUserAgentOverrideParams};
 use chromiumoxide::{Browser, BrowserConfig};
 use futures_util::stream::StreamExt;
 use futures_util::Stream;
-use once_cell::sync::Lazy;
+use rand::Rng;
 use reqwest::header::{self, HeaderMap, HeaderValue};
 use reqwest::{Client, StatusCode};
 use serde::Deserialize;
 use serde_json::Value;
 use url::Url;
 
+use crate::cursor_persist::{load_checkpoint, save_checkpoint, Checkpoint, PersistCheckpointError};
 use crate::error::Error;
 use crate::header_persist::{load_headers, save_headers, PersistHeadersError};
+use crate::model::{Tweet, User};
+use crate::oauth::{self, OAuth1Credentials};
 
 pub struct TweetScraper {
     client: Client,
+    auth_mode: AuthMode,
     fetch_state: FetchState,
+    // Path to checkpoint pagination progress to after each page, if any, and whether the next
+    // `tweets`/`tweets_raw` call should keep `fetch_state` as rehydrated by `resume` instead of
+    // resetting it.
+    checkpoint_path: Option<PathBuf>,
+    resumed: bool,
+    retry_config: RetryConfig,
+    // The most recently observed `x-rate-limit-remaining`/`x-rate-limit-reset` headers, keyed by
+    // URL path. Search, tweet lookups, and user lookups hit distinct endpoints with independent
+    // Twitter rate-limit buckets, so they're tracked separately rather than one proactively
+    // pausing the others. A plain Mutex is enough since it's never held across an await point.
+    rate_limit: Mutex<HashMap<String, RateLimitState>>,
 }
 
 pub enum HeaderPersist {
@@ -28,6 +43,45 @@ pub enum HeaderPersist {
     None,
 }
 
+/// How requests to the search API are authenticated.
+pub enum AuthMode {
+    /// Use the guest token scraped from the `gt` cookie, capped at the guest-level API surface.
+    Guest,
+    /// Sign each request with OAuth 1.0a using a consumer key/secret and access token/secret.
+    OAuth1(OAuth1Credentials),
+}
+
+/// Tuning for [`get_json`]'s retry behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Maximum number of retries before a persistently failing request surfaces as
+    /// [`Error::BadStatus`] (or the underlying network error).
+    pub max_retries: u32,
+    /// Base delay that exponential backoff for network errors/408/5xx doubles from.
+    pub base_backoff: Duration,
+    /// Cap on both that exponential backoff and on how long a 429 pause waits for
+    /// `x-rate-limit-reset`, however far away the server says it is.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 8,
+            base_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The most recently observed rate-limit window for an endpoint.
+#[derive(Debug, Clone, Copy)]
+struct RateLimitState {
+    remaining: u64,
+    /// Epoch seconds at which the window resets.
+    reset: u64,
+}
+
 // State during stream iteration
 #[derive(Default)]
 struct FetchState {
@@ -38,6 +92,9 @@ struct FetchState {
     tweets_count: usize,
     cursor: Option<String>,
     errored: bool,
+    // Set once the bottom of the timeline has been reached (empty page or unchanging cursor),
+    // so the stream stops requesting further pages once the queue drains.
+    done: bool,
 }
 
 #[derive(Debug)]
@@ -49,17 +106,24 @@ static USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (K
 static ACCEPT_VALUE: &str = "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.9";
 static AUTHORIZATION_VALUE: &str = "Bearer AAAAAAAAAAAAAAAAAAAAANRILgAAAAAAnNwIzUejRCOuH5E6I8xnZz4puTs%3D1Zv7ttfk8LF81IUq16cHjhLTvJu4FA33AGWWjCpTnA";
 
+/// Exponential backoff with jitter for the given (1-indexed) retry attempt, doubling from
+/// `config.base_backoff` and capped at `config.max_backoff`.
+fn retry_backoff(attempt: u32, config: &RetryConfig) -> Duration {
+    let exponential = config.base_backoff.saturating_mul(1u32 << attempt.min(6));
+    let capped = exponential.min(config.max_backoff);
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=capped.as_millis() as u64 / 4));
+    (capped + jitter).min(config.max_backoff)
+}
+
 impl TweetScraper {
-    pub async fn initialize(header_persist: HeaderPersist) -> Result<Self, Error> {
-        // If requested, load headers from file, otherwise spawn chromium process to get headers
-        let headers = if let HeaderPersist::Load(p) = &header_persist {
-            // Load headers from file
-            load_headers(&p)
-                .await
-                .map_err(|e| Error::PersistHeaders(PersistHeadersError::Load(e, p.clone())))?
-        } else {
-            // Load headers from chromium
-            let browser_data = browser_data().await?;
+    pub async fn initialize(
+        header_persist: HeaderPersist,
+        auth_mode: AuthMode,
+        retry_config: RetryConfig,
+    ) -> Result<Self, Error> {
+        // OAuth-signed requests carry their own per-request Authorization header, so there's
+        // no bearer token or guest token to scrape from a browser session.
+        let headers = if let AuthMode::OAuth1(_) = &auth_mode {
             let mut headers = HeaderMap::new();
             headers.insert(header::ACCEPT, HeaderValue::from_static(ACCEPT_VALUE));
             headers.insert(
@@ -70,26 +134,25 @@ impl TweetScraper {
                 header::ACCEPT_LANGUAGE,
                 HeaderValue::from_static("en-US,en;q=0.9"),
             );
-            headers.insert(
-                header::UPGRADE_INSECURE_REQUESTS,
-                HeaderValue::from_static("1"),
-            );
-            headers.insert(
-                header::AUTHORIZATION,
-                HeaderValue::from_static(AUTHORIZATION_VALUE),
-            );
-            let guest_token = &browser_data
-                .cookies
-                .iter()
-                .find(|c| c.name == "gt")
-                .ok_or_else(|| Error::NoGuestToken)?
-                .value;
-            headers.insert(
-                "x-guest-token",
-                HeaderValue::from_str(guest_token).map_err(|_| Error::InvalidGuestToken)?,
-            );
             headers.insert(header::USER_AGENT, HeaderValue::from_static(USER_AGENT));
             headers
+        } else if let HeaderPersist::Load(p) = &header_persist {
+            // Replay persisted headers (bearer, guest token, cookies) directly through reqwest,
+            // skipping chromium entirely. Fall back to launching a browser if the file is
+            // missing or no longer parses, rather than failing outright.
+            match load_headers(&p).await {
+                Ok(headers) => headers,
+                Err(e) => {
+                    eprintln!(
+                        "could not load persisted headers from {:?} ({}), falling back to chromium",
+                        p, e
+                    );
+                    browser_headers().await?
+                }
+            }
+        } else {
+            // Load headers from chromium
+            browser_headers().await?
         };
 
         // Save headers
@@ -109,23 +172,84 @@ impl TweetScraper {
 
         Ok(Self {
             client,
+            auth_mode,
             fetch_state: Default::default(),
+            checkpoint_path: None,
+            resumed: false,
+            retry_config,
+            rate_limit: Mutex::new(HashMap::new()),
         })
     }
 
+    /// Persist `{query, cursor, tweets_count, min_id, done}` to `path` once each previously
+    /// fetched page has been fully drained and handed to the caller (and once more when the
+    /// bottom of the timeline is reached and its last page drained), so a scrape killed or
+    /// crashed partway through can pick back up with [`TweetScraper::resume`] instead of
+    /// restarting from the top of the timeline, and without skipping the page in flight when it
+    /// crashes.
+    pub fn checkpoint_to(&mut self, path: impl Into<PathBuf>) {
+        self.checkpoint_path = Some(path.into());
+    }
+
+    /// Rehydrate pagination state from a checkpoint previously written via
+    /// [`TweetScraper::checkpoint_to`], and keep checkpointing further pages to the same file.
+    /// The next call to `tweets`/`tweets_raw` must use the same query the checkpoint was taken
+    /// under; it will continue from the saved cursor instead of resetting to the first page.
+    pub async fn resume(&mut self, path: impl Into<PathBuf>) -> Result<(), Error> {
+        let path = path.into();
+        let checkpoint = load_checkpoint(&path)
+            .await
+            .map_err(|e| Error::PersistCheckpoint(PersistCheckpointError::Load(e, path.clone())))?;
+
+        self.fetch_state = FetchState {
+            query: checkpoint.query,
+            cursor: checkpoint.cursor,
+            tweets_count: checkpoint.tweets_count,
+            min_id: checkpoint.min_id,
+            done: checkpoint.done,
+            ..Default::default()
+        };
+        self.checkpoint_path = Some(path);
+        self.resumed = true;
+
+        Ok(())
+    }
+
+    /// Search for tweets, deserialized into the typed [`Tweet`] model. Use
+    /// [`TweetScraper::tweets_raw`] instead if a field you need isn't modeled yet.
     pub async fn tweets(
         &mut self,
         query: impl AsRef<str>,
         limit: Option<usize>,
         min_id: Option<u128>,
+    ) -> impl Stream<Item = Result<Tweet, Error>> + '_ {
+        self.tweets_raw(query, limit, min_id).await.map(|result| {
+            result.and_then(|value| {
+                serde_json::from_value(value).map_err(|e| Error::TweetParse(e.to_string()))
+            })
+        })
+    }
+
+    /// Search for tweets, yielding the untyped JSON object for each as scraped from the
+    /// `global_objects.tweets` map (with its `user` object already attached).
+    pub async fn tweets_raw(
+        &mut self,
+        query: impl AsRef<str>,
+        limit: Option<usize>,
+        min_id: Option<u128>,
     ) -> impl Stream<Item = Result<Value, Error>> + '_ {
-        // Reset internal state
-        self.fetch_state = FetchState {
-            query: query.as_ref().to_owned(),
-            limit,
-            min_id,
-            ..Default::default()
-        };
+        // Reset internal state, unless it was just rehydrated by `resume` for this same query.
+        if self.resumed {
+            self.resumed = false;
+            self.fetch_state.limit = limit;
+        } else {
+            self.fetch_state = FetchState {
+                query: query.as_ref().to_owned(),
+                limit,
+                min_id,
+                ..Default::default()
+            };
+        }
 
         futures_util::stream::unfold(self, |state| async {
             // Stop if previously errored
@@ -140,53 +264,56 @@ impl TweetScraper {
                 }
             }
 
-            let mut should_return_tweet = |tweet| {
-                // Stop if minimum tweet id reached
-                if let Some(min_id) = state.fetch_state.min_id {
-                    let parse_id = |tweet: &Value| -> Result<u128, Error> {
-                        let id = tweet["id_str"]
-                            .as_str()
-                            .ok_or_else(|| Error::TweetParse("no id_str key".into()))?
-                            .parse()
-                            .map_err(|e| Error::TweetParse(format!("invalid id_str: {e}")))?;
-                        Ok(id)
-                    };
-                    match parse_id(&tweet) {
-                        Ok(id) => {
-                            if id < min_id {
-                                return None;
-                            }
-                        }
-                        Err(e) => {
-                            state.fetch_state.errored = true;
-                            return Some(Err(e));
-                        }
-                    }
-                }
-
-                // Return next tweet
-                state.fetch_state.tweets_count += 1;
-                Some(Ok(tweet))
-            };
-
             // Try returning the next tweet if available
             if let Some(tweet) = state.fetch_state.tweets.pop_front() {
-                if let Some(r) = should_return_tweet(tweet) {
+                if let Some(r) = should_return_tweet(&mut state.fetch_state, tweet) {
                     return Some((r, state));
                 }
             }
 
+            // Stop once the bottom of the timeline has been reached and its last page drained,
+            // persisting the terminal `done` state only now, so a crash partway through that
+            // page still resumes it
+            if state.fetch_state.done {
+                persist_checkpoint(&state.checkpoint_path, &state.fetch_state).await;
+                return None;
+            }
+
+            // The queue is only ever drained above, never persisted, so the page it held has by
+            // now been fully handed to the caller: this is the last point where `fetch_state`
+            // reflects a cursor the caller has actually seen everything up to. Checkpoint here,
+            // before requesting a new page, rather than right after the fetch returns — doing it
+            // there would record the new page's cursor before any of its tweets were drained, so
+            // a crash before the caller finished consuming that page would permanently skip it on
+            // resume.
+            persist_checkpoint(&state.checkpoint_path, &state.fetch_state).await;
+
             // Scrape Twitter
             match query_twitter(
                 &state.client,
+                &state.auth_mode,
                 state.fetch_state.query.as_str(),
                 state.fetch_state.cursor.as_deref(),
+                &state.retry_config,
+                &state.rate_limit,
             )
             .await
             {
                 Ok((tweets, cursor)) => {
+                    // The bottom of the timeline is reached once a page comes back empty or the
+                    // cursor stops advancing; keep any tweets from this page but stop paginating.
+                    if tweets.is_empty() || state.fetch_state.cursor.as_deref() == Some(cursor.as_str())
+                    {
+                        state.fetch_state.done = true;
+                    }
                     state.fetch_state.tweets.extend(tweets.into_iter());
                     state.fetch_state.cursor = Some(cursor);
+
+                    // An empty last page leaves nothing to drain, so the stream ends below without
+                    // reaching the `done` check above: persist the terminal `done` state now.
+                    if state.fetch_state.done && state.fetch_state.tweets.is_empty() {
+                        persist_checkpoint(&state.checkpoint_path, &state.fetch_state).await;
+                    }
                 }
                 Err(e) => {
                     state.fetch_state.errored = true;
@@ -196,7 +323,7 @@ impl TweetScraper {
 
             // Try returning the next tweet if available
             if let Some(tweet) = state.fetch_state.tweets.pop_front() {
-                if let Some(r) = should_return_tweet(tweet) {
+                if let Some(r) = should_return_tweet(&mut state.fetch_state, tweet) {
                     return Some((r, state));
                 }
             }
@@ -204,6 +331,246 @@ impl TweetScraper {
             None
         })
     }
+
+    /// Look up a single tweet by id, reusing the scraper's auth/retry machinery. Useful for
+    /// hydrating a specific tweet (e.g. one that's quoted or replied to) without crafting a
+    /// search query that may not surface it. Parses the single-object `statuses/show.json`
+    /// response shape directly, unlike `parse_tweets`' `global_objects` timeline shape.
+    pub async fn tweet_by_id(&self, id: u128) -> Result<Tweet, Error> {
+        static URL: &str = "https://api.twitter.com/1.1/statuses/show.json";
+
+        let mut url =
+            Url::parse(URL).map_err(|_| Error::Internal("could not parse api url".into()))?;
+        url.query_pairs_mut()
+            .append_pair("id", &id.to_string())
+            .append_pair("tweet_mode", "extended")
+            .append_pair("include_entities", "true");
+
+        let json = get_json(
+            &self.client,
+            &self.auth_mode,
+            &url,
+            &self.retry_config,
+            &self.rate_limit,
+        )
+        .await?;
+        serde_json::from_value(json).map_err(|e| Error::TweetParse(e.to_string()))
+    }
+
+    /// Look up a user profile by numeric id.
+    pub async fn user_by_id(&self, id: u128) -> Result<User, Error> {
+        self.user_lookup("user_id", &id.to_string()).await
+    }
+
+    /// Look up a user profile by screen name.
+    pub async fn user_by_handle(&self, screen_name: impl AsRef<str>) -> Result<User, Error> {
+        self.user_lookup("screen_name", screen_name.as_ref()).await
+    }
+
+    /// Shared `users/show.json` lookup for [`TweetScraper::user_by_id`] and
+    /// [`TweetScraper::user_by_handle`], reusing the scraper's auth/retry machinery.
+    async fn user_lookup(&self, id_param: &str, value: &str) -> Result<User, Error> {
+        static URL: &str = "https://api.twitter.com/1.1/users/show.json";
+
+        let mut url =
+            Url::parse(URL).map_err(|_| Error::Internal("could not parse api url".into()))?;
+        url.query_pairs_mut().append_pair(id_param, value);
+
+        let json = get_json(
+            &self.client,
+            &self.auth_mode,
+            &url,
+            &self.retry_config,
+            &self.rate_limit,
+        )
+        .await?;
+        serde_json::from_value(json).map_err(|e| Error::TweetParse(e.to_string()))
+    }
+
+    /// Reconstruct the conversation thread containing `tweet_id`: walk
+    /// `in_reply_to_status_id_str` upward to the conversation root, then gather descendant
+    /// replies via a `conversation_id:` search, attaching quoted tweets inline. Deduplicates by
+    /// tweet id and tolerates cycles. Returns [`Error::IncompleteThread`] if a lookup fails
+    /// partway up the chain, since a truncated walk isn't the true conversation root and the
+    /// subsequent search would silently miss every reply. Returns the root followed by its
+    /// replies in chronological order, deserialized into the typed [`Tweet`] model.
+    pub async fn thread(&mut self, tweet_id: u128) -> Result<Vec<Tweet>, Error> {
+        let mut seen = HashSet::new();
+        seen.insert(tweet_id);
+
+        // Walk upward to the conversation root. The `statuses/show.json` lookup already embeds
+        // a full `quoted_status` object when one exists, so there's no separate attach step
+        // needed for the root the way there is for replies below.
+        let mut root = self.tweet_by_id(tweet_id).await?;
+        loop {
+            let parent_id = root
+                .in_reply_to_status_id
+                .as_deref()
+                .and_then(|s| s.parse::<u128>().ok());
+            let Some(parent_id) = parent_id else {
+                break;
+            };
+            if !seen.insert(parent_id) {
+                break;
+            }
+            // An error here (e.g. a deleted, suspended, or rate-limited parent) means the walk
+            // is cut short of the true conversation root. Since the later `conversation_id:`
+            // search only returns results when seeded with the true root, returning `root` as-is
+            // would silently produce a single-tweet pseudo-thread with no replies. Surface the
+            // failure instead of guessing.
+            root = self
+                .tweet_by_id(parent_id)
+                .await
+                .map_err(|_| Error::IncompleteThread(parent_id))?;
+        }
+
+        let root_id = root.id as u128;
+        // The walk's ids only guarded against cycles; the conversation search returns the
+        // starting tweet and its ancestors too, so dedupe replies against the root alone.
+        seen.clear();
+        seen.insert(root_id);
+
+        // Gather descendant replies across the conversation, deduplicating by id.
+        let mut replies = Vec::new();
+        {
+            let stream = self
+                .tweets_raw(format!("conversation_id:{root_id}"), None, None)
+                .await;
+            futures_util::pin_mut!(stream);
+            while let Some(result) = stream.next().await {
+                let reply = result?;
+                if let Some(id) = tweet_value_id(&reply) {
+                    if seen.insert(id) {
+                        replies.push(reply);
+                    }
+                }
+            }
+        }
+        replies.sort_by_key(|t| tweet_value_id(t).unwrap_or(0));
+
+        for reply in &mut replies {
+            self.attach_quoted_status(reply, &mut seen).await;
+        }
+
+        let replies = replies
+            .into_iter()
+            .map(|value| serde_json::from_value(value).map_err(|e| Error::TweetParse(e.to_string())));
+
+        std::iter::once(Ok(root)).chain(replies).collect()
+    }
+
+    /// If `tweet` quotes another tweet not already seen and it isn't already embedded under
+    /// `quoted_status`, fetch it and attach it there, guarding against a tweet quoting itself or
+    /// one already in the thread.
+    async fn attach_quoted_status(&self, tweet: &mut Value, seen: &mut HashSet<u128>) {
+        let Some(quoted_id) = tweet["quoted_status_id_str"]
+            .as_str()
+            .and_then(|s| s.parse::<u128>().ok())
+        else {
+            return;
+        };
+        if !seen.insert(quoted_id) {
+            return;
+        }
+
+        // `parse_tweets`' `attach_related_statuses` already embeds `quoted_status` inline when
+        // the quoted tweet was resolved from the same page's own `global_objects.tweets` map;
+        // only pay for a fresh `tweet_by_id` round trip when that didn't happen.
+        if !tweet["quoted_status"].is_null() {
+            return;
+        }
+
+        if let Ok(quoted) = self.tweet_by_id(quoted_id).await {
+            if let Ok(quoted) = serde_json::to_value(quoted) {
+                if let Some(obj) = tweet.as_object_mut() {
+                    obj.insert("quoted_status".to_owned(), quoted);
+                }
+            }
+        }
+    }
+}
+
+fn tweet_value_id(tweet: &Value) -> Option<u128> {
+    tweet["id_str"].as_str().and_then(|s| s.parse().ok())
+}
+
+/// Save `fetch_state` to `checkpoint_path`, if one was configured via
+/// [`TweetScraper::checkpoint_to`]. Logs and otherwise ignores a write failure rather than
+/// erroring the whole stream over it.
+async fn persist_checkpoint(checkpoint_path: &Option<PathBuf>, fetch_state: &FetchState) {
+    let Some(path) = checkpoint_path else {
+        return;
+    };
+
+    let checkpoint = Checkpoint {
+        query: fetch_state.query.clone(),
+        cursor: fetch_state.cursor.clone(),
+        tweets_count: fetch_state.tweets_count,
+        min_id: fetch_state.min_id,
+        done: fetch_state.done,
+    };
+    if let Err(e) = save_checkpoint(&checkpoint, path).await {
+        eprintln!("failed to persist checkpoint to {:?}: {}", path, e);
+    }
+}
+
+/// Applies `fetch_state.min_id` filtering and bumps `tweets_count` for a tweet popped off the
+/// queue. Returns `None` to skip it (below `min_id`), `Some(Err(_))` on a parse failure (also
+/// marking `fetch_state` errored), or `Some(Ok(tweet))` to yield it to the stream.
+///
+/// A free function taking `&mut FetchState` explicitly, rather than a closure capturing `state`,
+/// so its borrow doesn't outlive the call and block reading other `TweetScraper` fields (e.g.
+/// for checkpointing) later in the same `unfold` step.
+fn should_return_tweet(fetch_state: &mut FetchState, tweet: Value) -> Option<Result<Value, Error>> {
+    if let Some(min_id) = fetch_state.min_id {
+        match tweet_value_id(&tweet) {
+            Some(id) if id < min_id => return None,
+            Some(_) => {}
+            None => {
+                fetch_state.errored = true;
+                return Some(Err(Error::TweetParse("no id_str key".into())));
+            }
+        }
+    }
+
+    fetch_state.tweets_count += 1;
+    Some(Ok(tweet))
+}
+
+/// Launch chromium to scrape a fresh guest token and cookies, and assemble them into the
+/// header set used to authenticate guest-mode requests.
+async fn browser_headers() -> Result<HeaderMap, Error> {
+    let browser_data = browser_data().await?;
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, HeaderValue::from_static(ACCEPT_VALUE));
+    headers.insert(
+        header::ACCEPT_ENCODING,
+        HeaderValue::from_static("gzip, deflate, br"),
+    );
+    headers.insert(
+        header::ACCEPT_LANGUAGE,
+        HeaderValue::from_static("en-US,en;q=0.9"),
+    );
+    headers.insert(
+        header::UPGRADE_INSECURE_REQUESTS,
+        HeaderValue::from_static("1"),
+    );
+    headers.insert(
+        header::AUTHORIZATION,
+        HeaderValue::from_static(AUTHORIZATION_VALUE),
+    );
+    let guest_token = &browser_data
+        .cookies
+        .iter()
+        .find(|c| c.name == "gt")
+        .ok_or_else(|| Error::NoGuestToken)?
+        .value;
+    headers.insert(
+        "x-guest-token",
+        HeaderValue::from_str(guest_token).map_err(|_| Error::InvalidGuestToken)?,
+    );
+    headers.insert(header::USER_AGENT, HeaderValue::from_static(USER_AGENT));
+    Ok(headers)
 }
 
 /// Get cookies for twitter.com
@@ -251,8 +618,11 @@ async fn browser_data() -> Result<BrowserData, Error> {
 
 async fn query_twitter(
     client: &Client,
+    auth_mode: &AuthMode,
     query: impl AsRef<str>,
     cursor: Option<&str>,
+    retry_config: &RetryConfig,
+    rate_limit: &Mutex<HashMap<String, RateLimitState>>,
 ) -> Result<(Vec<Value>, String), Error> {
     static URL: &str = "https://api.twitter.com/2/search/adaptive.json";
 
@@ -292,33 +662,131 @@ async fn query_twitter(
         url.query_pairs_mut().append_pair("cursor", cursor);
     }
 
-    static RETRY_STATUS: Lazy<Vec<StatusCode>> =
-        Lazy::new(|| [StatusCode::TOO_MANY_REQUESTS, StatusCode::REQUEST_TIMEOUT].into());
-    let json = loop {
-        let response = client
-            .get(url.as_str())
-            .send()
-            .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+    let json = get_json(client, auth_mode, &url, retry_config, rate_limit).await?;
+    parse_tweets(json)
+}
+
+/// Epoch seconds right now, used to compare against `x-rate-limit-reset`.
+fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Read the `x-rate-limit-remaining`/`x-rate-limit-reset` headers off a response, if present.
+fn rate_limit_from_headers(headers: &header::HeaderMap) -> Option<RateLimitState> {
+    let remaining = headers
+        .get("x-rate-limit-remaining")?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()?;
+    let reset = headers
+        .get("x-rate-limit-reset")?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()?;
+    Some(RateLimitState { remaining, reset })
+}
+
+/// GET `url`, signing or authenticating it per `auth_mode`. Proactively pauses until the
+/// (capped) `x-rate-limit-reset` if the last response reported `x-rate-limit-remaining: 0`, and retries
+/// network errors, 429s, and 5xx responses up to `retry_config.max_retries` times: 429s wait
+/// until the (capped) reset time reported by the response, everything else backs off
+/// exponentially with jitter.
+async fn get_json(
+    client: &Client,
+    auth_mode: &AuthMode,
+    url: &Url,
+    retry_config: &RetryConfig,
+    rate_limit: &Mutex<HashMap<String, RateLimitState>>,
+) -> Result<Value, Error> {
+    let endpoint = url.path();
+    let mut attempt = 0;
+    loop {
+        // Copy the state out and drop the guard before any `.await` below; holding a
+        // MutexGuard across an await point would block every other concurrent `&self` caller
+        // (e.g. a concurrent tweet_by_id/user_by_id call) on `.lock()` for the whole sleep.
+        let state = rate_limit.lock().unwrap().get(endpoint).copied();
+        if let Some(state) = state {
+            if state.remaining == 0 {
+                let wait = Duration::from_secs(state.reset.saturating_sub(now_unix()))
+                    .min(retry_config.max_backoff);
+                if !wait.is_zero() {
+                    eprintln!("rate limit exhausted, pausing {wait:?} until reset");
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+
+        let mut request = client.get(url.as_str());
+        if let AuthMode::OAuth1(creds) = auth_mode {
+            request = request.header(header::AUTHORIZATION, oauth::authorize_get(url, creds)?);
+        }
+
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) if attempt < retry_config.max_retries => {
+                attempt += 1;
+                eprintln!(
+                    "network error: {e}, retrying (attempt {attempt}/{})",
+                    retry_config.max_retries
+                );
+                tokio::time::sleep(retry_backoff(attempt, retry_config)).await;
+                continue;
+            }
+            Err(e) => return Err(Error::Network(e.to_string())),
+        };
+
+        if let Some(state) = rate_limit_from_headers(response.headers()) {
+            rate_limit.lock().unwrap().insert(endpoint.to_owned(), state);
+        }
+
         if response.status().is_success() {
-            break response
+            return response
                 .json::<Value>()
                 .await
-                .map_err(|e| Error::TweetParse(e.to_string()))?;
+                .map_err(|e| Error::TweetParse(e.to_string()));
+        }
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(Error::InvalidGuestToken);
         }
 
-        if response.status().is_server_error() || RETRY_STATUS.contains(&response.status()) {
+        if response.status() != StatusCode::TOO_MANY_REQUESTS
+            && !response.status().is_server_error()
+            && response.status() != StatusCode::REQUEST_TIMEOUT
+        {
+            return Err(Error::BadStatus(response.status().as_u16()));
+        }
+        if attempt >= retry_config.max_retries {
+            return Err(Error::BadStatus(response.status().as_u16()));
+        }
+        attempt += 1;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            // Prefer the reset time just observed on this very response over whatever's in
+            // `rate_limit`, in case it raced with a concurrent-ish call.
+            let wait = rate_limit_from_headers(response.headers())
+                .map(|state| Duration::from_secs(state.reset.saturating_sub(now_unix())))
+                .unwrap_or_else(|| retry_backoff(attempt, retry_config))
+                .min(retry_config.max_backoff);
             eprintln!(
-                "received response status code: {}, waiting 60 seconds",
-                response.status().as_u16()
+                "rate limited (429), waiting {wait:?} until reset (attempt {attempt}/{})",
+                retry_config.max_retries
             );
-            tokio::time::sleep(Duration::from_secs(60)).await;
+            tokio::time::sleep(wait).await;
         } else {
-            return Err(Error::BadStatus(response.status().as_u16()));
+            eprintln!(
+                "received response status code: {}, retrying (attempt {attempt}/{})",
+                response.status().as_u16(),
+                retry_config.max_retries
+            );
+            tokio::time::sleep(retry_backoff(attempt, retry_config)).await;
         }
-    };
-
-    parse_tweets(json)
+    }
 }
 
 fn parse_tweets(json: Value) -> Result<(Vec<Value>, String), Error> {
@@ -349,7 +817,14 @@ fn parse_tweets(json: Value) -> Result<(Vec<Value>, String), Error> {
             }
         }
     }
-    let tweets: Vec<_> = tweets.into_values().rev().collect();
+
+    // Embed quoted/retweeted tweet bodies (with their own user already attached above) inline,
+    // guarding against a tweet quoting/retweeting itself or a cycle of tweets quoting each other.
+    let tweets: Vec<_> = tweets
+        .keys()
+        .rev()
+        .filter_map(|id| attach_related_statuses(&tweets, id, &mut HashSet::new()))
+        .collect();
 
     // Parse cursor
     let timeline_str =
@@ -366,3 +841,35 @@ fn parse_tweets(json: Value) -> Result<(Vec<Value>, String), Error> {
 
     Ok((tweets, cursor))
 }
+
+/// Clones `id`'s tweet out of `tweets` and recursively attaches its `quoted_status`/
+/// `retweeted_status`, if any, under those keys. `seen` guards a single top-level tweet's
+/// lookup chain against self-references and cycles; a ref that isn't in `tweets`, or that
+/// would revisit an id already on the chain, is skipped silently.
+fn attach_related_statuses(
+    tweets: &BTreeMap<String, Value>,
+    id: &str,
+    seen: &mut HashSet<String>,
+) -> Option<Value> {
+    if !seen.insert(id.to_owned()) {
+        return None;
+    }
+
+    let mut tweet = tweets.get(id)?.clone();
+    if let Some(obj) = tweet.as_object_mut() {
+        for (id_field, status_key) in [
+            ("quoted_status_id_str", "quoted_status"),
+            ("retweeted_status_id_str", "retweeted_status"),
+        ] {
+            let Some(related_id) = obj.get(id_field).and_then(Value::as_str).map(str::to_owned)
+            else {
+                continue;
+            };
+            if let Some(related) = attach_related_statuses(tweets, &related_id, seen) {
+                obj.insert(status_key.to_owned(), related);
+            }
+        }
+    }
+
+    Some(tweet)
+}